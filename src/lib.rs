@@ -0,0 +1,20 @@
+//! Extensions to the `SmallFp` small-prime-field model used by STARK-style provers
+//! (Mersenne31, BabyBear, ...).
+
+mod small_fp;
+mod small_fp_ext;
+mod small_fp_fft;
+mod small_fp_packed;
+mod small_fp_uniform;
+#[cfg(test)]
+mod test_configs;
+mod test_templates;
+
+pub use small_fp::{
+    mont_inv32, mont_mul, mont_r2, pow_mod, standard_add, standard_inverse, standard_mul,
+    standard_neg, standard_sub, SmallField, SmallFp, SmallFpConfig,
+};
+pub use small_fp_ext::{ExtConfig, SmallFpExt};
+pub use small_fp_fft::{compute_two_adic_root, compute_two_adicity, FftConfig, SmallFpFftField};
+pub use small_fp_packed::PackedSmallFp;
+pub use test_templates::{SmallFieldModulus, TestRng};
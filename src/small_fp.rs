@@ -0,0 +1,346 @@
+//! A compact prime-field model for STARK-friendly moduli that fit in a single machine word
+//! (Mersenne31, BabyBear, ...). Unlike a general `Fp` model, which stores an
+//! arbitrary-width `BigInt`, `SmallFp` stores its value as a single `u64` and
+//! lets the configuring type pick a `standard` (conditional-subtract) or `montgomery` reduction
+//! backend. Configs are written by hand against the [`SmallFpConfig`] trait (see
+//! `src/test_configs.rs`); the free functions in this module (`standard_*`, `mont_*`) are the
+//! building blocks a config uses to implement each backend.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Backend-agnostic description of a small prime field.
+pub trait SmallFpConfig: 'static + Copy + Clone + Send + Sync + Sized {
+    /// The field modulus `p`.
+    const MODULUS: u64;
+    /// A multiplicative generator of `F_p^*`.
+    const GENERATOR: u64;
+    /// The inverse of 2 mod `p`, i.e. `(p + 1) / 2` for an odd prime `p`, in the representation
+    /// domain (canonical for `standard`, Montgomery form for `montgomery`).
+    const TWO_INV: u64;
+    /// Whether this config uses the `montgomery` backend (as opposed to `standard`).
+    const IS_MONTGOMERY: bool = false;
+    /// `R^2 mod p`, where `R = 2^32`. Only meaningful when `IS_MONTGOMERY` is `true`.
+    const MONT_R2: u64 = 0;
+    /// `-p^{-1} mod 2^32`. Only meaningful when `IS_MONTGOMERY` is `true`.
+    const MONT_INV32: u32 = 0;
+
+    /// Add two representatives, returning a representative in the same domain.
+    fn add(a: u64, b: u64) -> u64;
+    /// Subtract two representatives, returning a representative in the same domain.
+    fn sub(a: u64, b: u64) -> u64;
+    /// Multiply two representatives, returning a representative in the same domain.
+    fn mul(a: u64, b: u64) -> u64;
+    /// Negate a representative.
+    fn neg(a: u64) -> u64;
+    /// Invert a nonzero representative; `None` for zero.
+    fn inverse(a: u64) -> Option<u64>;
+    /// Maps a canonical integer in `[0, p)` into whatever domain `add`/`mul` expect: the
+    /// identity for the `standard` backend, or Montgomery form for the `montgomery` backend.
+    fn from_canonical(canonical: u64) -> u64;
+    /// The inverse of `from_canonical`: recovers the canonical integer in `[0, p)`.
+    fn to_canonical(repr: u64) -> u64;
+}
+
+/// Binary exponentiation `base^exp mod modulus`, usable from `const` contexts so configs can
+/// derive constants like [`SmallFpConfig::TWO_INV`] or a two-adic root of unity at compile time.
+pub const fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    base %= modulus;
+    let mut acc: u64 = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = ((acc as u128 * base as u128) % modulus as u128) as u64;
+        }
+        base = ((base as u128 * base as u128) % modulus as u128) as u64;
+        exp >>= 1;
+    }
+    acc
+}
+
+/// `standard`-backend conditional-subtract addition: valid for canonical representatives of any
+/// modulus, and equally valid for Montgomery-form representatives (addition does not interact
+/// with the `R` scaling).
+pub const fn standard_add(a: u64, b: u64, modulus: u64) -> u64 {
+    let sum = a + b;
+    if sum >= modulus { sum - modulus } else { sum }
+}
+
+/// `standard`-backend conditional-subtract subtraction.
+pub const fn standard_sub(a: u64, b: u64, modulus: u64) -> u64 {
+    if a >= b { a - b } else { modulus - (b - a) }
+}
+
+/// `standard`-backend negation.
+pub const fn standard_neg(a: u64, modulus: u64) -> u64 {
+    if a == 0 { 0 } else { modulus - a }
+}
+
+/// `standard`-backend multiplication: widen to `u128`, then reduce mod `modulus`.
+pub const fn standard_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+/// `standard`-backend inversion via Fermat's little theorem (`modulus` must be prime).
+pub const fn standard_inverse(a: u64, modulus: u64) -> Option<u64> {
+    if a == 0 {
+        None
+    } else {
+        Some(pow_mod(a, modulus - 2, modulus))
+    }
+}
+
+/// Computes `-modulus^{-1} mod 2^32` by Newton's method, for use as a config's `MONT_INV32`.
+pub const fn mont_inv32(modulus: u64) -> u32 {
+    let p = modulus as u32;
+    let mut x = p;
+    let mut i = 0;
+    while i < 5 {
+        x = x.wrapping_mul(2u32.wrapping_sub(p.wrapping_mul(x)));
+        i += 1;
+    }
+    x.wrapping_neg()
+}
+
+/// Computes `R^2 mod p` where `R = 2^32`, for use as a config's `MONT_R2`.
+pub const fn mont_r2(modulus: u64) -> u64 {
+    ((1u128 << 64) % modulus as u128) as u64
+}
+
+/// Single-limb (32-bit) Montgomery multiplication: `a * b * R^{-1} mod p`, for `R = 2^32`.
+pub const fn mont_mul(a: u64, b: u64, modulus: u64, inv32: u32) -> u64 {
+    let t = a as u128 * b as u128;
+    let m = (t as u32).wrapping_mul(inv32);
+    let t2 = t + m as u128 * modulus as u128;
+    let u = (t2 >> 32) as u64;
+    if u >= modulus { u - modulus } else { u }
+}
+
+/// A field element implemented by [`SmallFp`] and, recursively, by [`crate::SmallFpExt`] towers
+/// built on top of it. Lets extension code (the norm-based inverse, the schoolbook
+/// multiply-and-reduce) be written once and reused for `Fp`, `Fp2`, `Fp4`, ...
+pub trait SmallField:
+    Copy
+    + Clone
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity. A function rather than a `const`, since for `SmallFp` it depends
+    /// on the config's backend (always `0`, but the backend still must map it into its domain).
+    fn zero() -> Self;
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Inverts a nonzero element; `None` for zero.
+    fn inverse(&self) -> Option<Self>;
+
+    /// The field's prime characteristic `p`. For [`SmallFp<C>`] this is `C::MODULUS`; for an
+    /// extension tower it is the characteristic of the innermost base field (extending a field
+    /// never changes its characteristic).
+    fn characteristic() -> u64;
+
+    /// The Frobenius endomorphism `x -> x^p`, computed by square-and-multiply against
+    /// [`SmallField::characteristic`]. On `F_p` itself this is the identity (Fermat's little
+    /// theorem: `a^p == a`); on an extension it is the nontrivial automorphism that generates
+    /// the tower's Galois group.
+    fn frobenius(&self) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut exp = Self::characteristic();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+/// An element of the small prime field described by `C`, stored as a representative in whatever
+/// domain `C`'s backend uses (canonical for `standard`, Montgomery form for `montgomery`).
+#[derive(Copy, Clone, Eq)]
+pub struct SmallFp<C: SmallFpConfig>(pub(crate) u64, PhantomData<C>);
+
+impl<C: SmallFpConfig> PartialEq for SmallFp<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C: SmallFpConfig> core::hash::Hash for SmallFp<C> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<C: SmallFpConfig> SmallFp<C> {
+    /// Wraps an already-in-domain representative without checking it is canonical. Prefer
+    /// [`SmallFp::from_u64`] unless you already have a value in `C`'s representation domain
+    /// (e.g. from another `SmallFpConfig` method).
+    pub(crate) const fn new_unchecked(repr: u64) -> Self {
+        Self(repr, PhantomData)
+    }
+
+    /// Builds an element from an arbitrary `u64`, reducing mod `C::MODULUS` and mapping it into
+    /// `C`'s representation domain.
+    pub fn from_u64(value: u64) -> Self {
+        Self::new_unchecked(C::from_canonical(value % C::MODULUS))
+    }
+
+    /// Recovers the canonical integer representative in `[0, C::MODULUS)`.
+    pub fn to_u64(&self) -> u64 {
+        C::to_canonical(self.0)
+    }
+
+    /// The field's configured generator, as an element.
+    pub fn generator() -> Self {
+        Self::from_u64(C::GENERATOR)
+    }
+
+    /// `true` if this element is the additive identity.
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The inverse of 2, as an element (`C::TWO_INV`, already in `C`'s representation domain).
+    pub fn two_inv() -> Self {
+        Self::new_unchecked(C::TWO_INV)
+    }
+
+    /// Little-endian encoding of the canonical representative.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.to_u64().to_le_bytes()
+    }
+
+    /// Decodes a little-endian canonical representative, rejecting encodings `>= C::MODULUS`
+    /// (an out-of-range encoding is never produced by `to_bytes`, so decoding one indicates
+    /// corrupted or adversarial input).
+    pub fn from_bytes(bytes: &[u8; 8]) -> Option<Self> {
+        let value = u64::from_le_bytes(*bytes);
+        if value >= C::MODULUS {
+            None
+        } else {
+            Some(Self::from_u64(value))
+        }
+    }
+
+    /// Square root via Tonelli-Shanks (`C::MODULUS` must be an odd prime). Returns `None` if
+    /// `self` is not a quadratic residue. Takes the `p ≡ 3 (mod 4)` fast path when possible
+    /// (covers M31); falls back to the general algorithm otherwise (covers BabyBear, whose
+    /// two-adicity is 27).
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        let p = C::MODULUS;
+        let a = self.to_u64();
+        if pow_mod(a, (p - 1) / 2, p) != 1 {
+            return None;
+        }
+
+        let mut q = p - 1;
+        let mut s = 0u32;
+        while q % 2 == 0 {
+            q /= 2;
+            s += 1;
+        }
+        if s == 1 {
+            return Some(Self::from_u64(pow_mod(a, (p + 1).div_ceil(4), p)));
+        }
+
+        let mut z = 2u64;
+        while pow_mod(z, (p - 1) / 2, p) != p - 1 {
+            z += 1;
+        }
+
+        let mut m = s;
+        let mut c = pow_mod(z, q, p);
+        let mut t = pow_mod(a, q, p);
+        let mut r = pow_mod(a, q.div_ceil(2), p);
+        loop {
+            if t == 1 {
+                return Some(Self::from_u64(r));
+            }
+            let mut i = 0u32;
+            let mut temp = t;
+            while temp != 1 {
+                temp = standard_mul(temp, temp, p);
+                i += 1;
+            }
+            let b = pow_mod(c, 1u64 << (m - i - 1), p);
+            m = i;
+            c = standard_mul(b, b, p);
+            t = standard_mul(t, c, p);
+            r = standard_mul(r, b, p);
+        }
+    }
+}
+
+impl<C: SmallFpConfig> From<u32> for SmallFp<C> {
+    fn from(value: u32) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl<C: SmallFpConfig> fmt::Display for SmallFp<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_u64())
+    }
+}
+
+impl<C: SmallFpConfig> fmt::Debug for SmallFp<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SmallFp({})", self.to_u64())
+    }
+}
+
+impl<C: SmallFpConfig> Add for SmallFp<C> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new_unchecked(C::add(self.0, rhs.0))
+    }
+}
+
+impl<C: SmallFpConfig> Sub for SmallFp<C> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new_unchecked(C::sub(self.0, rhs.0))
+    }
+}
+
+impl<C: SmallFpConfig> Mul for SmallFp<C> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new_unchecked(C::mul(self.0, rhs.0))
+    }
+}
+
+impl<C: SmallFpConfig> Neg for SmallFp<C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new_unchecked(C::neg(self.0))
+    }
+}
+
+impl<C: SmallFpConfig> SmallField for SmallFp<C> {
+    fn zero() -> Self {
+        Self::new_unchecked(0)
+    }
+
+    fn one() -> Self {
+        Self::from_u64(1)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        C::inverse(self.0).map(Self::new_unchecked)
+    }
+
+    fn characteristic() -> u64 {
+        C::MODULUS
+    }
+}
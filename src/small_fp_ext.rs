@@ -0,0 +1,268 @@
+//! A binomial extension tower `F[X] / (X^D - W)` over any [`SmallField`] `F`, for running STARK
+//! provers at the security level a single small field (M31, BabyBear, ...) cannot provide.
+//! Mirrors the cubic `XFieldElement` construction over Goldilocks used by the `twenty-first`
+//! crate, generalized to a configurable non-residue `W`, degree `D`, and base field `F`.
+//!
+//! Taking `F` itself to be a `SmallFpExt` lets extensions be stacked into a tower, e.g. a quartic
+//! field as `Fp2 -> Fp4` (the "quartic over the complex extension `Fp[i]`" construction M31 needs
+//! for enough soundness), rather than requiring one large closed-form degree-4 formula.
+
+use crate::small_fp::SmallField;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Describes a degree-`D` binomial extension `F[X] / (X^D - W)` of the field `F`.
+///
+/// `W` must be a non-residue: `X^D - W` must be irreducible over `F`. Only `D == 2` and `D == 3`
+/// have a closed-form [`SmallFpExt::inverse`] implemented below (the norm-based trick); higher
+/// degrees are built as a tower of `D == 2` / `D == 3` steps instead (see the module docs).
+pub trait ExtConfig<F: SmallField, const D: usize>: 'static + Copy + Clone + Send + Sync {
+    /// The non-residue `W` such that `X^D = W` defines the extension.
+    const NONRESIDUE: F;
+}
+
+/// An element `c_0 + c_1 X + ... + c_{D-1} X^{D-1}` of the extension `F[X] / (X^D - W)`.
+#[derive(Copy, Clone)]
+pub struct SmallFpExt<F: SmallField, E: ExtConfig<F, D>, const D: usize> {
+    /// Coefficients in increasing order of `X`'s power.
+    pub coeffs: [F; D],
+    _config: PhantomData<E>,
+}
+
+impl<F: SmallField, E: ExtConfig<F, D>, const D: usize> PartialEq for SmallFpExt<F, E, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<F: SmallField + core::fmt::Debug, E: ExtConfig<F, D>, const D: usize> core::fmt::Debug
+    for SmallFpExt<F, E, D>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SmallFpExt").field("coeffs", &self.coeffs).finish()
+    }
+}
+
+impl<F: SmallField, E: ExtConfig<F, D>, const D: usize> SmallFpExt<F, E, D> {
+    /// Builds an extension element from its coefficient vector.
+    pub const fn new(coeffs: [F; D]) -> Self {
+        Self { coeffs, _config: PhantomData }
+    }
+
+    /// Embeds a base-field element as the constant term.
+    pub fn from_base(c0: F) -> Self {
+        let mut coeffs = [F::zero(); D];
+        coeffs[0] = c0;
+        Self::new(coeffs)
+    }
+
+    /// Componentwise addition.
+    pub fn add(&self, other: &Self) -> Self {
+        let mut coeffs = self.coeffs;
+        for (c, o) in coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *c = *c + *o;
+        }
+        Self::new(coeffs)
+    }
+
+    /// Componentwise subtraction.
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut coeffs = self.coeffs;
+        for (c, o) in coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *c = *c - *o;
+        }
+        Self::new(coeffs)
+    }
+
+    /// Schoolbook polynomial multiplication followed by reducing every term of degree `>= D` via
+    /// `X^D = W`: coefficient `c_{D+k}` of the raw (degree `2D - 2`) product folds back into
+    /// position `k`, scaled by `W`. The wide accumulator is heap-allocated so this works for any
+    /// `D`, not just degrees that fit a hardcoded stack buffer.
+    #[allow(clippy::needless_range_loop)] // `i + j` indexes a third array; not a plain walk.
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut wide = vec![F::zero(); 2 * D - 1];
+        for i in 0..D {
+            for j in 0..D {
+                wide[i + j] = wide[i + j] + self.coeffs[i] * other.coeffs[j];
+            }
+        }
+        let mut coeffs = [F::zero(); D];
+        coeffs[..D].copy_from_slice(&wide[..D]);
+        for k in 0..(D - 1) {
+            coeffs[k] = coeffs[k] + wide[D + k] * E::NONRESIDUE;
+        }
+        Self::new(coeffs)
+    }
+
+    /// Negation.
+    pub fn neg(&self) -> Self {
+        let mut coeffs = self.coeffs;
+        for c in coeffs.iter_mut() {
+            *c = -*c;
+        }
+        Self::new(coeffs)
+    }
+
+    /// Inverts a nonzero element via the norm-based trick: multiplying by the product of the
+    /// nontrivial Galois conjugates lands in the base field `F`, where inversion is cheap and the
+    /// extension's inverse is that base inverse times the same conjugate product.
+    ///
+    /// Implemented for `D == 2` (quadratic, conjugate `(a0, -a1)`) and `D == 3` (cubic; the
+    /// formula below is the standard one used for arkworks' `CubicExtField`). Other degrees
+    /// should be built as a tower of these two (see module docs) rather than adding a bespoke
+    /// closed form per degree.
+    pub fn inverse(&self) -> Option<Self> {
+        match D {
+            2 => {
+                let a0 = self.coeffs[0];
+                let a1 = self.coeffs[1];
+                let norm = a0 * a0 - E::NONRESIDUE * (a1 * a1);
+                let norm_inv = norm.inverse()?;
+                let mut coeffs = [F::zero(); D];
+                coeffs[0] = a0 * norm_inv;
+                coeffs[1] = -(a1 * norm_inv);
+                Some(Self::new(coeffs))
+            }
+            3 => {
+                let a0 = self.coeffs[0];
+                let a1 = self.coeffs[1];
+                let a2 = self.coeffs[2];
+                let w = E::NONRESIDUE;
+                let t0 = a0 * a0 - w * (a1 * a2);
+                let t1 = w * (a2 * a2) - a0 * a1;
+                let t2 = a1 * a1 - a0 * a2;
+                let norm = w * (a1 * t2) + a0 * t0 + w * (a2 * t1);
+                let norm_inv = norm.inverse()?;
+                let mut coeffs = [F::zero(); D];
+                coeffs[0] = t0 * norm_inv;
+                coeffs[1] = t1 * norm_inv;
+                coeffs[2] = t2 * norm_inv;
+                Some(Self::new(coeffs))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<F: SmallField, E: ExtConfig<F, D>, const D: usize> Add for SmallFpExt<F, E, D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        SmallFpExt::add(&self, &rhs)
+    }
+}
+
+impl<F: SmallField, E: ExtConfig<F, D>, const D: usize> Sub for SmallFpExt<F, E, D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        SmallFpExt::sub(&self, &rhs)
+    }
+}
+
+impl<F: SmallField, E: ExtConfig<F, D>, const D: usize> Mul for SmallFpExt<F, E, D> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        SmallFpExt::mul(&self, &rhs)
+    }
+}
+
+impl<F: SmallField, E: ExtConfig<F, D>, const D: usize> Neg for SmallFpExt<F, E, D> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        SmallFpExt::neg(&self)
+    }
+}
+
+/// Any binomial extension of a [`SmallField`] is itself a [`SmallField`], so extensions can be
+/// stacked into a tower (e.g. `Fp2` as the base field of a further quadratic `Fp4`).
+impl<F: SmallField, E: ExtConfig<F, D>, const D: usize> SmallField for SmallFpExt<F, E, D> {
+    fn zero() -> Self {
+        Self::new([F::zero(); D])
+    }
+
+    fn one() -> Self {
+        Self::from_base(F::one())
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        SmallFpExt::inverse(self)
+    }
+
+    fn characteristic() -> u64 {
+        F::characteristic()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_configs::{BabyBearFp2, BabyBearFp4, M31Fp2, M31Fp4, M31};
+    use crate::{ExtConfig, SmallField, SmallFpExt};
+
+    /// A cubic extension of M31 itself (not used by either concrete field, but exercises the
+    /// `D == 3` closed-form inverse the quadratic-only towers above never touch).
+    #[derive(Copy, Clone)]
+    struct M31Fp3Config;
+    impl ExtConfig<M31, 3> for M31Fp3Config {
+        // 7 (M31's generator) is not a cube mod `p`, so `X^3 - 7` is irreducible.
+        const NONRESIDUE: M31 = M31::new_unchecked(7);
+    }
+    type M31Fp3 = SmallFpExt<M31, M31Fp3Config, 3>;
+
+    #[test]
+    fn quadratic_mul_is_consistent_with_inverse() {
+        let a = M31Fp2::new([M31::from_u64(3), M31::from_u64(5)]);
+        let b = M31Fp2::new([M31::from_u64(7), M31::from_u64(11)]);
+        let c = a.mul(&b);
+        let a_inv = a.inverse().expect("nonzero element must invert");
+        assert_eq!(c.mul(&a_inv).coeffs, b.coeffs);
+        assert_eq!(a.mul(&a_inv), M31Fp2::one());
+    }
+
+    #[test]
+    fn cubic_mul_is_consistent_with_inverse() {
+        let a = M31Fp3::new([M31::from_u64(3), M31::from_u64(5), M31::from_u64(9)]);
+        let b = M31Fp3::new([M31::from_u64(7), M31::from_u64(11), M31::from_u64(2)]);
+        let c = a.mul(&b);
+        let a_inv = a.inverse().expect("nonzero element must invert");
+        assert_eq!(c.mul(&a_inv).coeffs, b.coeffs);
+        assert_eq!(a.mul(&a_inv), M31Fp3::one());
+    }
+
+    #[test]
+    fn m31_quartic_tower_inverse_round_trips() {
+        let base = M31Fp2::new([M31::from_u64(13), M31::from_u64(17)]);
+        let a = M31Fp4::new([base, M31Fp2::new([M31::from_u64(1), M31::from_u64(4)])]);
+        let a_inv = a.inverse().expect("nonzero element must invert");
+        assert_eq!(a.mul(&a_inv), M31Fp4::one());
+    }
+
+    #[test]
+    fn babybear_quartic_tower_inverse_round_trips() {
+        use crate::test_configs::BabyBear;
+        let base = BabyBearFp2::new([BabyBear::from_u64(13), BabyBear::from_u64(17)]);
+        let a = BabyBearFp4::new([base, BabyBearFp2::new([BabyBear::from_u64(1), BabyBear::from_u64(4)])]);
+        let a_inv = a.inverse().expect("nonzero element must invert");
+        assert_eq!(a.mul(&a_inv), BabyBearFp4::one());
+    }
+
+    /// The Frobenius endomorphism `x -> x^p` generates a degree-`D` extension's Galois group, so
+    /// applying it `D` times must return to the original element (`x^(p^D) == x`, since every
+    /// element of `GF(p^D)` satisfies that order bound). A bug in `characteristic()` or the
+    /// square-and-multiply in `SmallField::frobenius` would break this for any nontrivial tower.
+    #[test]
+    fn quadratic_frobenius_has_order_two() {
+        let a = M31Fp2::new([M31::from_u64(13), M31::from_u64(17)]);
+        assert_eq!(a.frobenius().frobenius(), a);
+    }
+
+    #[test]
+    fn quartic_tower_frobenius_has_order_four() {
+        let base = M31Fp2::new([M31::from_u64(13), M31::from_u64(17)]);
+        let a = M31Fp4::new([base, M31Fp2::new([M31::from_u64(1), M31::from_u64(4)])]);
+        let once = a.frobenius();
+        let twice = once.frobenius();
+        let thrice = twice.frobenius();
+        assert_ne!(once, a, "Frobenius should not be trivial on a genuine extension element");
+        assert_ne!(twice, a, "Frobenius must not have order two on a genuine quartic element");
+        assert_eq!(thrice.frobenius(), a, "applying Frobenius 4 times must return the original element");
+    }
+}
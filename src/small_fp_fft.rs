@@ -0,0 +1,159 @@
+//! `FftField`-style support for [`SmallFp`], exploiting the high two-adicity of fields like
+//! BabyBear (`p - 1 = 2^27 * t`) to look up primitive roots of unity instead of recomputing them
+//! on every call. Mirrors the `phf_map!` of precomputed primitive roots used for the Goldilocks
+//! field in the `twenty-first` crate.
+
+use crate::small_fp::{pow_mod, SmallFp, SmallFpConfig};
+
+/// Factors `modulus - 1 = 2^s * t` (`t` odd) and returns `s`. `const fn` so a config can compute
+/// its own [`FftConfig::TWO_ADICITY`] at compile time rather than hand-counting factors of two.
+pub const fn compute_two_adicity(modulus: u64) -> u32 {
+    let mut t = modulus - 1;
+    let mut s = 0;
+    while t.is_multiple_of(2) {
+        t /= 2;
+        s += 1;
+    }
+    s
+}
+
+/// Computes `generator^((modulus - 1) / 2^two_adicity) mod modulus`, a primitive
+/// `2^two_adicity`-th root of unity, as a canonical integer (not yet mapped into a backend's
+/// representation domain — pass the result through `SmallFpConfig::from_canonical` first if `C`
+/// uses the `montgomery` backend).
+pub const fn compute_two_adic_root(modulus: u64, generator: u64, two_adicity: u32) -> u64 {
+    let mut t = modulus - 1;
+    let mut i = 0;
+    while i < two_adicity {
+        t /= 2;
+        i += 1;
+    }
+    pow_mod(generator, t, modulus)
+}
+
+/// Extends [`SmallFpConfig`] with the two-adic data needed for radix-2 NTTs: `p - 1 = 2^S * T`
+/// with `T` odd, and a primitive `2^S`-th root of unity. Written by hand against
+/// [`compute_two_adicity`]/[`compute_two_adic_root`] (there is no derive macro in this crate; see
+/// `src/test_configs.rs` for worked examples), since config structs here are all hand-written.
+///
+/// `ROOTS_OF_UNITY` is an optional precomputed table of primitive `2^k`-th roots, indexed by `k`.
+/// For any `k` past the end of the table (every `k`, when it is empty),
+/// [`SmallFpFftField::get_root_of_unity`] falls back to repeated squaring from
+/// `TWO_ADIC_ROOT_OF_UNITY`. Fields with tiny two-adicity (e.g. M31) should leave it empty, since
+/// a table buys nothing at that size; fields like BabyBear, with two-adicity large enough that
+/// provers call `get_root_of_unity` for many different sizes, benefit from tabulating it.
+pub trait FftConfig: SmallFpConfig {
+    /// `S`, the largest power of two dividing `p - 1`.
+    const TWO_ADICITY: u32;
+    /// A primitive `2^TWO_ADICITY`-th root of unity, i.e. `GENERATOR^((p - 1) / 2^TWO_ADICITY)`.
+    const TWO_ADIC_ROOT_OF_UNITY: SmallFp<Self>;
+    /// Precomputed primitive `2^k`-th roots of unity, indexed by `k`, for `k` in
+    /// `0..ROOTS_OF_UNITY.len()`; this may be any prefix of `0..=TWO_ADICITY`. Empty when the
+    /// config opts out of tabulation.
+    const ROOTS_OF_UNITY: &'static [SmallFp<Self>];
+}
+
+/// FFT-field operations available on any [`SmallFp<C>`] whose config implements [`FftConfig`].
+pub trait SmallFpFftField: Sized {
+    /// The primitive `2^s`-th root of unity, where `s` is the field's two-adicity.
+    fn two_adic_root_of_unity() -> Self;
+
+    /// A primitive `n`-th root of unity, for `n` a power of two with `n <= 2^s`. Returns `None`
+    /// if `n` is not a power of two or exceeds the field's two-adicity.
+    fn get_root_of_unity(n: u64) -> Option<Self>;
+}
+
+impl<C: FftConfig> SmallFpFftField for SmallFp<C> {
+    fn two_adic_root_of_unity() -> Self {
+        C::TWO_ADIC_ROOT_OF_UNITY
+    }
+
+    fn get_root_of_unity(n: u64) -> Option<Self> {
+        if !n.is_power_of_two() {
+            return None;
+        }
+        let k = n.trailing_zeros();
+        if k > C::TWO_ADICITY {
+            return None;
+        }
+        if let Some(root) = C::ROOTS_OF_UNITY.get(k as usize) {
+            return Some(*root);
+        }
+        // No table entry for this size (e.g. a field that opted out of tabulation): derive it
+        // from the top-level root by repeated squaring down from `2^TWO_ADICITY` to `2^k`.
+        let mut root = C::TWO_ADIC_ROOT_OF_UNITY;
+        for _ in 0..(C::TWO_ADICITY - k) {
+            root = SmallFp::new_unchecked(C::mul(root.0, root.0));
+        }
+        Some(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SmallFpFftField;
+    use crate::test_configs::{BabyBear, M31};
+
+    #[test]
+    fn babybear_two_adicity_matches_p_minus_one() {
+        // BabyBear: p - 1 = 2013265920 = 2^27 * 15.
+        let root = BabyBear::two_adic_root_of_unity();
+        let mut power = root;
+        for _ in 0..27 {
+            power = power * power;
+        }
+        assert_eq!(power, BabyBear::from_u64(1), "root is not 2^27-th order");
+    }
+
+    #[test]
+    fn babybear_get_root_of_unity_has_correct_order() {
+        for k in 0..=5u32 {
+            let n = 1u64 << k;
+            let root = BabyBear::get_root_of_unity(n).expect("power-of-two n must have a root");
+            let mut power = root;
+            for _ in 1..n {
+                power = power * root;
+            }
+            assert_eq!(power, BabyBear::from_u64(1), "root of unity has wrong order for n = {n}");
+        }
+        assert!(BabyBear::get_root_of_unity(3).is_none(), "3 is not a power of two");
+    }
+
+    #[test]
+    fn m31_has_tiny_two_adicity_and_no_table() {
+        // M31: p - 1 = 2147483646 = 2 * (2^30 - 1), so two-adicity is just 1.
+        use crate::test_configs::M31Config;
+        use crate::FftConfig;
+        assert!(M31Config::ROOTS_OF_UNITY.is_empty(), "M31 should not tabulate its roots");
+        assert_eq!(M31::get_root_of_unity(4), None);
+        let root = M31::get_root_of_unity(2).expect("M31 has a primitive square root of unity");
+        assert_eq!(root * root, M31::from_u64(1));
+    }
+
+    #[test]
+    fn babybear_roots_table_is_populated_and_primitive() {
+        use crate::test_configs::BabyBearConfig;
+        use crate::FftConfig;
+
+        // The table is what makes `get_root_of_unity` skip the repeated-squaring fallback for
+        // small sizes; if it's empty, this request's stated goal (look roots up directly instead
+        // of recomputing them) silently isn't realized.
+        assert!(!BabyBearConfig::ROOTS_OF_UNITY.is_empty(), "BabyBear's table must not be empty");
+
+        for (k, &root) in BabyBearConfig::ROOTS_OF_UNITY.iter().enumerate() {
+            let n = 1u64 << k;
+            let mut power = BabyBear::from_u64(1);
+            let mut half_power = None;
+            for i in 1..=n {
+                power = power * root;
+                if i == n / 2 {
+                    half_power = Some(power);
+                }
+            }
+            assert_eq!(power, BabyBear::from_u64(1), "table root for k = {k} has the wrong order");
+            if let Some(half) = half_power {
+                assert_ne!(half, BabyBear::from_u64(1), "table root for k = {k} is not primitive");
+            }
+        }
+    }
+}
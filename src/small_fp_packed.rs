@@ -0,0 +1,182 @@
+//! `PackedSmallFp`: a SIMD-lane companion to [`SmallFp`] that stores `LANES` field elements
+//! contiguously and operates on all of them at once, for batch-heavy prover workloads (FFT
+//! butterflies, batch inversion via Montgomery's trick) where per-element overhead dominates.
+
+use crate::small_fp::{mont_mul, standard_add, standard_mul, standard_neg, standard_sub, SmallFp, SmallFpConfig};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// `LANES` elements of `SmallFp<C>`, packed for vectorized arithmetic.
+///
+/// Addition/subtraction/negation are a single branchless conditional-subtract per lane — that
+/// formula is valid for both backends, since Montgomery form does not change how addition works,
+/// only multiplication. Multiplication dispatches on `C::IS_MONTGOMERY` (a compile-time constant
+/// once `C` is fixed, so this is not a per-call branch after monomorphization): the `montgomery`
+/// backend runs the single-limb Montgomery reduction (`mont_mul`) per lane, and the `standard`
+/// backend runs a widen-then-reduce `x mod p` per lane. Each lane is computed independently of
+/// the others, so this is the scalar body a `core::simd`/intrinsics rewrite would vectorize.
+#[derive(Copy, Clone)]
+pub struct PackedSmallFp<C: SmallFpConfig, const LANES: usize> {
+    lanes: [SmallFp<C>; LANES],
+}
+
+// `PartialEq`/`Eq`/`Debug` are written by hand rather than derived: deriving them would add a
+// spurious `C: PartialEq`/`C: Debug` bound on the config type itself, which none of this crate's
+// configs implement (see `SmallFp`'s own manual impls for the same reason).
+impl<C: SmallFpConfig, const LANES: usize> PartialEq for PackedSmallFp<C, LANES> {
+    fn eq(&self, other: &Self) -> bool {
+        self.lanes == other.lanes
+    }
+}
+
+impl<C: SmallFpConfig, const LANES: usize> Eq for PackedSmallFp<C, LANES> {}
+
+impl<C: SmallFpConfig, const LANES: usize> core::fmt::Debug for PackedSmallFp<C, LANES> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PackedSmallFp").field("lanes", &self.lanes).finish()
+    }
+}
+
+impl<C: SmallFpConfig, const LANES: usize> PackedSmallFp<C, LANES> {
+    /// Number of elements packed per vector.
+    pub const WIDTH: usize = LANES;
+
+    /// Packs a slice of exactly `LANES` elements into one vector.
+    pub fn pack(elems: &[SmallFp<C>]) -> Self {
+        assert_eq!(elems.len(), LANES, "expected exactly {LANES} elements to pack");
+        let mut lanes = [elems[0]; LANES];
+        lanes.copy_from_slice(elems);
+        Self { lanes }
+    }
+
+    /// Unpacks back into a plain array of `LANES` elements.
+    pub fn unpack(&self) -> [SmallFp<C>; LANES] {
+        self.lanes
+    }
+
+    /// Lane-wise addition: one conditional-subtract per lane, independent of backend.
+    pub fn add(&self, other: &Self) -> Self {
+        let mut lanes = self.lanes;
+        for (l, o) in lanes.iter_mut().zip(other.lanes.iter()) {
+            *l = SmallFp::new_unchecked(standard_add(l.0, o.0, C::MODULUS));
+        }
+        Self { lanes }
+    }
+
+    /// Lane-wise subtraction: one conditional-subtract per lane, independent of backend.
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut lanes = self.lanes;
+        for (l, o) in lanes.iter_mut().zip(other.lanes.iter()) {
+            *l = SmallFp::new_unchecked(standard_sub(l.0, o.0, C::MODULUS));
+        }
+        Self { lanes }
+    }
+
+    /// Lane-wise multiplication: a vectorized Montgomery reduction when `C` uses the
+    /// `montgomery` backend, or a vectorized widen-and-reduce `x mod p` when it uses `standard`.
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut lanes = self.lanes;
+        for (l, o) in lanes.iter_mut().zip(other.lanes.iter()) {
+            let product = if C::IS_MONTGOMERY {
+                mont_mul(l.0, o.0, C::MODULUS, C::MONT_INV32)
+            } else {
+                standard_mul(l.0, o.0, C::MODULUS)
+            };
+            *l = SmallFp::new_unchecked(product);
+        }
+        Self { lanes }
+    }
+
+    /// Lane-wise negation: one conditional-subtract per lane, independent of backend.
+    pub fn neg(&self) -> Self {
+        let mut lanes = self.lanes;
+        for l in lanes.iter_mut() {
+            *l = SmallFp::new_unchecked(standard_neg(l.0, C::MODULUS));
+        }
+        Self { lanes }
+    }
+}
+
+impl<C: SmallFpConfig, const LANES: usize> Add for PackedSmallFp<C, LANES> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        PackedSmallFp::add(&self, &rhs)
+    }
+}
+
+impl<C: SmallFpConfig, const LANES: usize> Sub for PackedSmallFp<C, LANES> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        PackedSmallFp::sub(&self, &rhs)
+    }
+}
+
+impl<C: SmallFpConfig, const LANES: usize> Mul for PackedSmallFp<C, LANES> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        PackedSmallFp::mul(&self, &rhs)
+    }
+}
+
+impl<C: SmallFpConfig, const LANES: usize> Neg for PackedSmallFp<C, LANES> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        PackedSmallFp::neg(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackedSmallFp;
+    use crate::test_configs::{BabyBear, BabyBearConfig, M31, M31Config};
+
+    #[test]
+    #[allow(clippy::needless_range_loop)]
+    fn standard_backend_matches_scalar_arithmetic() {
+        let a: [M31; 4] = [M31::from_u64(3), M31::from_u64(5), M31::from_u64(7), M31::from_u64(11)];
+        let b: [M31; 4] = [M31::from_u64(13), M31::from_u64(17), M31::from_u64(19), M31::from_u64(23)];
+        let packed_a = PackedSmallFp::<M31Config, 4>::pack(&a);
+        let packed_b = PackedSmallFp::<M31Config, 4>::pack(&b);
+
+        let sum = packed_a.add(&packed_b).unpack();
+        let product = packed_a.mul(&packed_b).unpack();
+        for i in 0..4 {
+            assert_eq!(sum[i], a[i] + b[i]);
+            assert_eq!(product[i], a[i] * b[i]);
+        }
+    }
+
+    #[test]
+    fn core_ops_match_inherent_methods() {
+        let a: [M31; 4] = [M31::from_u64(3), M31::from_u64(5), M31::from_u64(7), M31::from_u64(11)];
+        let b: [M31; 4] = [M31::from_u64(13), M31::from_u64(17), M31::from_u64(19), M31::from_u64(23)];
+        let packed_a = PackedSmallFp::<M31Config, 4>::pack(&a);
+        let packed_b = PackedSmallFp::<M31Config, 4>::pack(&b);
+
+        assert_eq!(packed_a + packed_b, packed_a.add(&packed_b));
+        assert_eq!(packed_a - packed_b, packed_a.sub(&packed_b));
+        assert_eq!(packed_a * packed_b, packed_a.mul(&packed_b));
+        assert_eq!(-packed_a, packed_a.neg());
+    }
+
+    #[test]
+    #[allow(clippy::needless_range_loop)]
+    fn montgomery_backend_matches_scalar_arithmetic() {
+        let a: [BabyBear; 4] =
+            [BabyBear::from_u64(3), BabyBear::from_u64(5), BabyBear::from_u64(7), BabyBear::from_u64(11)];
+        let b: [BabyBear; 4] = [
+            BabyBear::from_u64(13),
+            BabyBear::from_u64(17),
+            BabyBear::from_u64(19),
+            BabyBear::from_u64(23),
+        ];
+        let packed_a = PackedSmallFp::<BabyBearConfig, 4>::pack(&a);
+        let packed_b = PackedSmallFp::<BabyBearConfig, 4>::pack(&b);
+
+        let sum = packed_a.add(&packed_b).unpack();
+        let product = packed_a.mul(&packed_b).unpack();
+        for i in 0..4 {
+            assert_eq!(sum[i], a[i] + b[i]);
+            assert_eq!(product[i], a[i] * b[i]);
+        }
+    }
+}
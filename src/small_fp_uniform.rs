@@ -0,0 +1,64 @@
+//! Uniform hash-to-field for [`SmallFp`], so sponge/Fiat-Shamir transcripts can turn raw
+//! randomness into a field element without hand-rolled rejection sampling.
+
+use crate::small_fp::{SmallFp, SmallFpConfig};
+
+impl<C: SmallFpConfig> SmallFp<C> {
+    /// Interprets `bytes` (big-endian) as a wide integer and reduces it modulo `C::MODULUS`,
+    /// mirroring the `from_uniform_bytes` constructor in `ff`/`halo2curves`.
+    ///
+    /// `N` must supply at least ~128 bits more than `log2(p)` for the result to be statistically
+    /// indistinguishable from uniform (e.g. 32 bytes of input for a 31-bit field modulus, which
+    /// supplies 256 >= 31 + 128 bits); this is checked with a debug assertion rather than a
+    /// `where` bound, since `SmallFp` moduli are only known at the value level, not the type
+    /// level.
+    pub fn from_uniform_bytes<const N: usize>(bytes: &[u8; N]) -> Self {
+        debug_assert!(
+            (N as u32) * 8 >= 64u32.saturating_sub(C::MODULUS.leading_zeros()) + 128,
+            "from_uniform_bytes: {N} input bytes is not enough to make the output of a {}-bit \
+             modulus statistically close to uniform",
+            64 - C::MODULUS.leading_zeros(),
+        );
+
+        // Horner's method over a double-width accumulator: safe because `C::MODULUS` fits in a
+        // u64 and the running remainder is always `< C::MODULUS` before folding in the next byte.
+        let mut acc: u128 = 0;
+        for &byte in bytes.iter() {
+            acc = (acc * 256 + byte as u128) % (C::MODULUS as u128);
+        }
+        let canonical = acc as u64;
+        Self::new_unchecked(C::from_canonical(canonical))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_configs::{BabyBear, M31};
+
+    #[test]
+    fn output_is_always_in_range() {
+        for seed in 0..64u8 {
+            let mut bytes = [0u8; 32];
+            bytes[0] = seed;
+            bytes[31] = seed.wrapping_mul(31).wrapping_add(7);
+            assert!(M31::from_uniform_bytes(&bytes).to_u64() < 2_147_483_647);
+            assert!(BabyBear::from_uniform_bytes(&bytes).to_u64() < 2_013_265_921);
+        }
+    }
+
+    #[test]
+    fn small_values_below_modulus_round_trip_exactly() {
+        // A 32-byte big-endian encoding whose value is already `< p` must reduce to itself.
+        let mut bytes = [0u8; 32];
+        bytes[28..].copy_from_slice(&42u32.to_be_bytes());
+        assert_eq!(M31::from_uniform_bytes(&bytes).to_u64(), 42);
+        assert_eq!(BabyBear::from_uniform_bytes(&bytes).to_u64(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not enough")]
+    fn rejects_too_few_bytes_in_debug() {
+        let bytes = [0u8; 4];
+        let _ = M31::from_uniform_bytes(&bytes);
+    }
+}
@@ -0,0 +1,216 @@
+//! Concrete [`SmallFpConfig`] and [`ExtConfig`] instances shared by this crate's tests. Written
+//! by hand against the trait (there is no `#[derive(SmallFpConfig)]` proc macro in this crate),
+//! mirroring the fields used in `examples/small_field_tests.rs`.
+
+use crate::small_fp::{
+    mont_inv32, mont_mul, mont_r2, standard_add, standard_inverse, standard_mul, standard_neg,
+    standard_sub, SmallFpConfig,
+};
+use crate::small_fp_fft::{compute_two_adic_root, compute_two_adicity, FftConfig};
+use crate::{ExtConfig, SmallFp, SmallFpExt};
+
+/// M31: `2^31 - 1`, `standard` backend.
+#[derive(Copy, Clone)]
+pub struct M31Config;
+
+impl SmallFpConfig for M31Config {
+    const MODULUS: u64 = 2_147_483_647;
+    const GENERATOR: u64 = 7;
+    const TWO_INV: u64 = Self::MODULUS.div_ceil(2);
+
+    fn add(a: u64, b: u64) -> u64 {
+        standard_add(a, b, Self::MODULUS)
+    }
+    fn sub(a: u64, b: u64) -> u64 {
+        standard_sub(a, b, Self::MODULUS)
+    }
+    fn mul(a: u64, b: u64) -> u64 {
+        standard_mul(a, b, Self::MODULUS)
+    }
+    fn neg(a: u64) -> u64 {
+        standard_neg(a, Self::MODULUS)
+    }
+    fn inverse(a: u64) -> Option<u64> {
+        standard_inverse(a, Self::MODULUS)
+    }
+    fn from_canonical(canonical: u64) -> u64 {
+        canonical
+    }
+    fn to_canonical(repr: u64) -> u64 {
+        repr
+    }
+}
+
+pub type M31 = SmallFp<M31Config>;
+
+impl FftConfig for M31Config {
+    const TWO_ADICITY: u32 = compute_two_adicity(Self::MODULUS);
+    const TWO_ADIC_ROOT_OF_UNITY: M31 = SmallFp::new_unchecked(compute_two_adic_root(
+        Self::MODULUS,
+        Self::GENERATOR,
+        compute_two_adicity(Self::MODULUS),
+    ));
+    // M31's two-adicity is tiny (1), so a table buys nothing; fall back to repeated squaring.
+    const ROOTS_OF_UNITY: &'static [M31] = &[];
+}
+
+/// BabyBear: `2^31 - 2^27 + 1`, `montgomery` backend.
+#[derive(Copy, Clone)]
+pub struct BabyBearConfig;
+
+impl SmallFpConfig for BabyBearConfig {
+    const MODULUS: u64 = 2_013_265_921;
+    const GENERATOR: u64 = 31;
+    const IS_MONTGOMERY: bool = true;
+    const MONT_R2: u64 = mont_r2(Self::MODULUS);
+    const MONT_INV32: u32 = mont_inv32(Self::MODULUS);
+    const TWO_INV: u64 = mont_mul(
+        Self::MODULUS.div_ceil(2),
+        mont_r2(Self::MODULUS),
+        Self::MODULUS,
+        mont_inv32(Self::MODULUS),
+    );
+
+    fn add(a: u64, b: u64) -> u64 {
+        standard_add(a, b, Self::MODULUS)
+    }
+    fn sub(a: u64, b: u64) -> u64 {
+        standard_sub(a, b, Self::MODULUS)
+    }
+    fn mul(a: u64, b: u64) -> u64 {
+        mont_mul(a, b, Self::MODULUS, Self::MONT_INV32)
+    }
+    fn neg(a: u64) -> u64 {
+        standard_neg(a, Self::MODULUS)
+    }
+    fn inverse(a: u64) -> Option<u64> {
+        let canonical = Self::to_canonical(a);
+        standard_inverse(canonical, Self::MODULUS).map(Self::from_canonical)
+    }
+    fn from_canonical(canonical: u64) -> u64 {
+        mont_mul(canonical, Self::MONT_R2, Self::MODULUS, Self::MONT_INV32)
+    }
+    fn to_canonical(repr: u64) -> u64 {
+        mont_mul(repr, 1, Self::MODULUS, Self::MONT_INV32)
+    }
+}
+
+pub type BabyBear = SmallFp<BabyBearConfig>;
+
+/// Precomputes primitive `2^k`-th roots of unity for `k` in `0..BABYBEAR_ROOTS_TABLE.len()`, in
+/// Montgomery form. Covers the small-to-medium NTT sizes (up to `2^7 = 128`) a prover looks up
+/// most often; larger sizes still work via `get_root_of_unity`'s repeated-squaring fallback. A
+/// full `0..=27` table is a worthwhile optimization in a real prover, but building a `const` array
+/// whose length depends on another `const` isn't ergonomic without unstable `generic_const_exprs`,
+/// so this is a fixed-size prefix instead.
+const fn babybear_roots_table<const N: usize>() -> [BabyBear; N] {
+    let mut table = [SmallFp::new_unchecked(0); N];
+    let mut k = 0;
+    while k < N {
+        let raw = compute_two_adic_root(BabyBearConfig::MODULUS, BabyBearConfig::GENERATOR, k as u32);
+        let mont = mont_mul(raw, BabyBearConfig::MONT_R2, BabyBearConfig::MODULUS, BabyBearConfig::MONT_INV32);
+        table[k] = SmallFp::new_unchecked(mont);
+        k += 1;
+    }
+    table
+}
+
+const BABYBEAR_ROOTS_TABLE: [BabyBear; 8] = babybear_roots_table();
+
+impl FftConfig for BabyBearConfig {
+    const TWO_ADICITY: u32 = compute_two_adicity(Self::MODULUS);
+    const TWO_ADIC_ROOT_OF_UNITY: BabyBear = SmallFp::new_unchecked(mont_mul(
+        compute_two_adic_root(Self::MODULUS, Self::GENERATOR, compute_two_adicity(Self::MODULUS)),
+        Self::MONT_R2,
+        Self::MODULUS,
+        Self::MONT_INV32,
+    ));
+    const ROOTS_OF_UNITY: &'static [BabyBear] = &BABYBEAR_ROOTS_TABLE;
+}
+
+/// A tiny field (modulus 101, `standard` backend) cheap enough to run with a large repeat count
+/// or to exhaustively enumerate, used by [`crate::test_small_field!`]'s lower-weight test runs.
+#[derive(Copy, Clone)]
+pub struct TinyConfig;
+
+impl SmallFpConfig for TinyConfig {
+    const MODULUS: u64 = 101;
+    const GENERATOR: u64 = 2;
+    const TWO_INV: u64 = Self::MODULUS.div_ceil(2);
+
+    fn add(a: u64, b: u64) -> u64 {
+        standard_add(a, b, Self::MODULUS)
+    }
+    fn sub(a: u64, b: u64) -> u64 {
+        standard_sub(a, b, Self::MODULUS)
+    }
+    fn mul(a: u64, b: u64) -> u64 {
+        standard_mul(a, b, Self::MODULUS)
+    }
+    fn neg(a: u64) -> u64 {
+        standard_neg(a, Self::MODULUS)
+    }
+    fn inverse(a: u64) -> Option<u64> {
+        standard_inverse(a, Self::MODULUS)
+    }
+    fn from_canonical(canonical: u64) -> u64 {
+        canonical
+    }
+    fn to_canonical(repr: u64) -> u64 {
+        repr
+    }
+}
+
+pub type Tiny = SmallFp<TinyConfig>;
+
+/// `M31[i] = M31[X] / (X^2 + 1)`: the complex extension of M31 (`-1` is a non-residue since
+/// `p ≡ 3 mod 4`).
+#[derive(Copy, Clone)]
+pub struct M31Fp2Config;
+impl ExtConfig<M31, 2> for M31Fp2Config {
+    const NONRESIDUE: M31 = M31::new_unchecked(M31Config::MODULUS - 1);
+}
+pub type M31Fp2 = SmallFpExt<M31, M31Fp2Config, 2>;
+
+/// `M31[i][Y] / (Y^2 - (2 + i))`: the quartic tower over M31 the request calls for ("a quartic
+/// over the complex extension `Fp[i]`"). `2 + i` is a non-square in `M31[i]` because its norm,
+/// `5`, is a non-residue mod `p`.
+#[derive(Copy, Clone)]
+pub struct M31Fp4Config;
+impl ExtConfig<M31Fp2, 2> for M31Fp4Config {
+    const NONRESIDUE: M31Fp2 = M31Fp2::new([M31::new_unchecked(2), M31::new_unchecked(1)]);
+}
+pub type M31Fp4 = SmallFpExt<M31Fp2, M31Fp4Config, 2>;
+
+/// `BabyBear[x] / (x^2 - 11)`: the first step of BabyBear's quartic extension (the request's
+/// `X^4 - 11`, realized as a tower of two quadratics so the generic `D == 2` norm-trick inverse
+/// applies at each step instead of needing a bespoke quartic formula).
+#[derive(Copy, Clone)]
+pub struct BabyBearFp2Config;
+impl ExtConfig<BabyBear, 2> for BabyBearFp2Config {
+    // `BabyBear` uses the `montgomery` backend, so the literal `11` must be mapped into
+    // Montgomery form the same way `SmallFpConfig::from_canonical` would, rather than stored raw.
+    const NONRESIDUE: BabyBear = BabyBear::new_unchecked(mont_mul(
+        11,
+        BabyBearConfig::MONT_R2,
+        BabyBearConfig::MODULUS,
+        BabyBearConfig::MONT_INV32,
+    ));
+}
+pub type BabyBearFp2 = SmallFpExt<BabyBear, BabyBearFp2Config, 2>;
+
+/// `BabyBear[x][y] / (y^2 - x)`: the second step, completing the quartic tower.
+#[derive(Copy, Clone)]
+pub struct BabyBearFp4Config;
+impl ExtConfig<BabyBearFp2, 2> for BabyBearFp4Config {
+    const NONRESIDUE: BabyBearFp2 = BabyBearFp2::new([
+        BabyBear::new_unchecked(0),
+        BabyBear::new_unchecked(mont_mul(
+            1,
+            BabyBearConfig::MONT_R2,
+            BabyBearConfig::MODULUS,
+            BabyBearConfig::MONT_INV32,
+        )),
+    ]);
+}
+pub type BabyBearFp4 = SmallFpExt<BabyBearFp2, BabyBearFp4Config, 2>;
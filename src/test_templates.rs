@@ -0,0 +1,178 @@
+//! `test_small_field!`: a structured test-generation macro for [`SmallFp`] instantiations,
+//! following the same "opt-in test suite" shape as the field-testing macros in `halo2curves`.
+//! The base invocation only exercises arithmetic; appending feature names pulls in extra
+//! `#[test]` functions for that field.
+//!
+//! This crate has no `rand`/`ark_ff`/`ark_serialize` dependency, so the generated tests draw
+//! randomness from [`TestRng`] (a small, deterministic splitmix64 generator good enough for
+//! property tests) and check serialization/sqrt/constants against `SmallFp`'s own inherent
+//! methods rather than `ark_ff::Field`/`ark_serialize` trait impls.
+
+/// A splitmix64 generator, seeded deterministically per test run. Not suitable for anything
+/// beyond generating varied inputs for property tests.
+#[doc(hidden)]
+pub struct TestRng(u64);
+
+impl TestRng {
+    #[doc(hidden)]
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    #[doc(hidden)]
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// 32 bytes: enough uniform input for [`crate::SmallFp::from_uniform_bytes`]'s ~128-bits-of-
+    /// slack requirement on any modulus this crate's configs use (up to the 31-bit fields here).
+    #[doc(hidden)]
+    pub fn next_bytes32(&mut self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for chunk in out.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        out
+    }
+}
+
+/// Generates a `#[test]`-annotated module exercising `$field` (a `SmallFp<...>` type alias).
+///
+/// `small_prime_field` (always generated, whether or not it is listed) checks associativity of
+/// `+` and `*` and distributivity of `*` over `+` on random elements. Any of the
+/// following may be appended to pull in more coverage:
+/// - `sqrt`: checks `sqrt(x)^2 == x` for random squares, and that non-residues square-root to
+///   `None`.
+/// - `constants`: checks `two_inv()` is the inverse of 2 and `generator()` is nonzero.
+/// - `serialization`: checks `from_bytes(to_bytes(x)) == Some(x)` round-trips, and that an
+///   encoding `>= MODULUS` is rejected.
+/// - `frobenius`: checks `x^p == frobenius(x)`. On the prime fields this macro is invoked against,
+///   `p` is the field's own characteristic, so Fermat's little theorem pins `frobenius(x) == x`;
+///   the nontrivial case (Frobenius as an order-`D` automorphism of a real extension tower) is
+///   covered directly in `small_fp_ext.rs`'s own tests rather than through this macro, since
+///   there is no `from_uniform_bytes` (or any `test_small_field!`-compatible constructor) for
+///   `SmallFpExt` yet.
+///
+/// ```ignore
+/// test_small_field!(m31; M31; sqrt, constants, serialization, frobenius);
+/// ```
+#[macro_export]
+macro_rules! test_small_field {
+    ($mod_name:ident; $field:ty; $($feature:ident),* $(,)?) => {
+        $crate::test_small_field!(32; $mod_name; $field; $($feature),*);
+    };
+    ($repeat:literal; $mod_name:ident; $field:ty; $($feature:ident),* $(,)?) => {
+        #[cfg(test)]
+        mod $mod_name {
+            use super::*;
+
+            #[test]
+            fn small_prime_field() {
+                let mut rng = $crate::test_templates::TestRng::new(0x5eed);
+                for _ in 0..$repeat {
+                    let a = <$field>::from_uniform_bytes(&rng.next_bytes32());
+                    let b = <$field>::from_uniform_bytes(&rng.next_bytes32());
+                    let c = <$field>::from_uniform_bytes(&rng.next_bytes32());
+                    assert_eq!(a + (b + c), (a + b) + c, "addition is not associative");
+                    assert_eq!(a * (b * c), (a * b) * c, "multiplication is not associative");
+                    assert_eq!(a * (b + c), a * b + a * c, "multiplication does not distribute over addition");
+                }
+            }
+
+            $crate::test_small_field!(@feature $field; $($feature),*);
+        }
+    };
+
+    // `small_prime_field` is always run by the base arm above; accept it as a feature name too
+    // so existing `test_small_field!(name; Field; small_prime_field)` call sites keep compiling.
+    (@feature $field:ty; small_prime_field $(, $rest:ident)*) => {
+        $crate::test_small_field!(@feature $field; $($rest),*);
+    };
+    (@feature $field:ty; sqrt $(, $rest:ident)*) => {
+        #[test]
+        fn sqrt() {
+            let mut rng = $crate::test_templates::TestRng::new(0x5caf);
+            for _ in 0..32 {
+                let x = <$field>::from_uniform_bytes(&rng.next_bytes32());
+                let square = x * x;
+                let root = square.sqrt().expect("a perfect square must have a square root");
+                assert_eq!(root * root, square, "sqrt(x)^2 != x for a known square");
+                // A square times the generator (a non-residue) is never a square.
+                if !x.is_zero() {
+                    let non_residue = square * <$field>::generator();
+                    assert!(non_residue.sqrt().is_none(), "a non-residue must not have a square root");
+                }
+            }
+        }
+        $crate::test_small_field!(@feature $field; $($rest),*);
+    };
+    (@feature $field:ty; constants $(, $rest:ident)*) => {
+        #[test]
+        fn constants() {
+            let two = <$field>::from_u64(2);
+            assert_eq!(two * <$field>::two_inv(), <$field>::from_u64(1), "two_inv() is not the inverse of 2");
+            assert!(!<$field>::generator().is_zero(), "generator() must be nonzero");
+        }
+        $crate::test_small_field!(@feature $field; $($rest),*);
+    };
+    (@feature $field:ty; serialization $(, $rest:ident)*) => {
+        #[test]
+        fn serialization() {
+            let mut rng = $crate::test_templates::TestRng::new(0x5e51a1);
+            for _ in 0..32 {
+                let x = <$field>::from_uniform_bytes(&rng.next_bytes32());
+                let bytes = x.to_bytes();
+                let recovered = <$field>::from_bytes(&bytes).expect("to_bytes output must decode");
+                assert_eq!(x, recovered, "to_bytes/from_bytes did not round-trip");
+            }
+
+            // An encoding of `MODULUS` itself is out of range and must be rejected.
+            let modulus_bytes = <$field as $crate::SmallFieldModulus>::modulus().to_le_bytes();
+            assert!(<$field>::from_bytes(&modulus_bytes).is_none());
+        }
+        $crate::test_small_field!(@feature $field; $($rest),*);
+    };
+    (@feature $field:ty; frobenius $(, $rest:ident)*) => {
+        #[test]
+        fn frobenius() {
+            let mut rng = $crate::test_templates::TestRng::new(0xf70be);
+            for _ in 0..32 {
+                let x = <$field>::from_uniform_bytes(&rng.next_bytes32());
+                assert_eq!(
+                    $crate::SmallField::frobenius(&x),
+                    x,
+                    "Frobenius endomorphism must fix every element of the prime field F_p",
+                );
+            }
+        }
+        $crate::test_small_field!(@feature $field; $($rest),*);
+    };
+    (@feature $field:ty;) => {};
+}
+
+/// Exposes a config's modulus from the field type alone, so
+/// `test_small_field!`'s `serialization` feature can build an out-of-range encoding without
+/// naming the config type.
+pub trait SmallFieldModulus {
+    /// The field's modulus, as a plain `u64`.
+    fn modulus() -> u64;
+}
+
+impl<C: crate::SmallFpConfig> SmallFieldModulus for crate::SmallFp<C> {
+    fn modulus() -> u64 {
+        C::MODULUS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_configs::{BabyBear, Tiny, M31};
+
+    test_small_field!(m31; M31; sqrt, constants, serialization, frobenius);
+    test_small_field!(babybear; BabyBear; sqrt, constants, serialization, frobenius);
+    test_small_field!(100; tiny; Tiny; small_prime_field, constants, serialization, frobenius);
+}